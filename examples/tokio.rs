@@ -1,9 +1,7 @@
 use anyhow::{Result, bail};
-use absurd_future::absurd_future;
+use absurd_future::Supervisor;
 use std::{convert::Infallible, time::Duration};
 
-use tokio::task::JoinSet;
-
 #[tokio::main]
 async fn main() -> Result<()> {
     let _result = main_inner().await?;
@@ -30,29 +28,10 @@ async fn task_two() -> Result<Infallible> {
 }
 
 async fn main_inner() -> Result<Infallible> {
-    let mut join_set = JoinSet::new();
-
-    join_set.spawn(absurd_future(task_one()));
-    join_set.spawn(task_two());
+    let mut supervisor = Supervisor::new();
 
-    match join_set.join_next().await {
-        Some(result) => match result {
-            Ok(res) => match res {
-                Ok(_res) => bail!("Impossible: Infallible witnessed!"),
-                Err(e) => {
-                    join_set.abort_all();
-                    bail!("Task exited with {e}")
-                },
-                            },
-            Err(e) => {
-                join_set.abort_all();
-                bail!("Task exited with {e}")
-            }
-        },
-        None => {
-            join_set.abort_all();
-            bail!("No tasks found in task set")
-        }
-    }
+    supervisor.spawn_forever(task_one());
+    supervisor.spawn_fallible(task_two());
 
+    bail!(supervisor.run().await)
 }
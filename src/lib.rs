@@ -1,12 +1,15 @@
-//! A future adapter that turns a future that never resolves (i.e., returns `Infallible`)
-//! into a future that can resolve to any type.
+//! A future adapter that turns a future that never resolves (i.e., returns an
+//! [`Uninhabited`] type such as `Infallible`) into a future that can resolve to any type.
 //!
 //! This is useful in scenarios where you have a task that runs forever (like a background
 //! service) but need to integrate it into an API that expects a specific return type,
 //! such as `tokio::task::JoinSet`.
 //!
 //! The core of this crate is the [`AbsurdFuture`] struct and the convenient
-//! [`absurd_future`] function.
+//! [`absurd_future`] function. The same technique applies to streams via
+//! [`AbsurdStream`]/[`absurd_stream`]. For supervising a set of such never-returning
+//! tasks directly, see [`Supervisor`]. When there's no real inner future at all, see
+//! [`pending_as`] for a placeholder future of type `T` that is always pending.
 //!
 //! For a detailed explanation of the motivation behind this crate and the concept of
 //! uninhabited types in Rust async code, see the blog post:
@@ -28,7 +31,7 @@
 //!     }
 //! }
 //!
-//! async fn main() {
+//! fn main() {
 //!     // We have a task that never returns, but we want to use it in a
 //!     // context that expects a `Result<(), &str>`.
 //!     let future = task_that_never_returns();
@@ -37,18 +40,29 @@
 //!     let adapted_future: _ = absurd_future::<_, Result<(), &str>>(future);
 //!
 //!     // This adapted future will now pend forever, just like the original,
-//!     // but its type signature satisfies the requirement.
+//!     // but its type signature satisfies the requirement. We don't poll it
+//!     // here, since doing so would hang forever.
+//!     drop(adapted_future);
 //! }
 //! ```
 
 use std::{
-    convert::Infallible,
     future::Future,
     marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
 };
 
+mod pending;
+mod stream;
+mod supervisor;
+mod uninhabited;
+
+pub use pending::{PendingAs, pending_as};
+pub use stream::{AbsurdStream, absurd_stream};
+pub use supervisor::Supervisor;
+pub use uninhabited::Uninhabited;
+
 /// Turn a never-returning future into a future yielding any desired type.
 ///
 /// This struct is created by the [`absurd_future`] function.
@@ -57,35 +71,46 @@ use std::{
 /// interface expecting a concrete output type. Because the inner future never
 /// resolves, this future will also never resolve, so the output type `T` is
 /// never actually produced.
+///
+/// `inner` is the only structurally pinned field, so `AbsurdFuture` stores it inline
+/// instead of boxing it; no allocation is needed to wrap a future.
 #[must_use = "futures do nothing unless polled"]
 pub struct AbsurdFuture<F, T> {
-    inner: Pin<Box<F>>,
+    inner: F,
     _marker: PhantomData<fn() -> T>,
 }
 
 impl<F, T> AbsurdFuture<F, T> {
     /// Creates a new `AbsurdFuture` that wraps the given future.
     ///
-    /// The inner future must have an output type of `Infallible`.
+    /// The inner future must have an [`Uninhabited`] output type, such as `Infallible`.
     pub fn new(inner: F) -> Self {
         Self {
-            inner: Box::pin(inner),
+            inner,
             _marker: PhantomData,
         }
     }
 }
 
+impl<F, T> Unpin for AbsurdFuture<F, T> where F: Unpin {}
+
 impl<F, T> Future for AbsurdFuture<F, T>
 where
-    F: Future<Output = Infallible>,
+    F: Future,
+    F::Output: Uninhabited,
 {
     type Output = T;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let inner = self.get_mut().inner.as_mut();
+        // SAFETY: `inner` is the only structurally pinned field. `_marker` is a
+        // zero-sized `PhantomData<fn() -> T>` and is never treated as pinned (it holds
+        // no data and `AbsurdFuture`'s `Unpin` impl is already keyed off `F` alone), so
+        // projecting a `Pin<&mut F>` out of `Pin<&mut Self>` upholds the pinning
+        // invariants required by `map_unchecked_mut`.
+        let inner: Pin<&mut F> = unsafe { self.map_unchecked_mut(|s| &mut s.inner) };
         match Future::poll(inner, cx) {
             Poll::Pending => Poll::Pending,
-            Poll::Ready(never) => match never {},
+            Poll::Ready(never) => Poll::Ready(never.absurd()),
         }
     }
 }
@@ -96,11 +121,12 @@ where
 ///
 /// # Type Parameters
 ///
-/// - `F`: The type of the inner future, which must return `Infallible`.
+/// - `F`: The type of the inner future, whose output type must be [`Uninhabited`].
 /// - `T`: The desired output type for the wrapped future. This is often inferred.
 pub fn absurd_future<F, T>(future: F) -> AbsurdFuture<F, T>
 where
-    F: Future<Output = Infallible>,
+    F: Future,
+    F::Output: Uninhabited,
 {
     AbsurdFuture::new(future)
 }
@@ -0,0 +1,75 @@
+//! A future that is always pending, with no inner future required.
+
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A future that never resolves, typed as `T`.
+///
+/// This struct is created by the [`pending_as`] function. See its documentation for
+/// details, and [`std::future::Pending`], the standard library future this mirrors.
+#[must_use = "futures do nothing unless polled"]
+pub struct PendingAs<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> PendingAs<T> {
+    fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for PendingAs<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingAs").finish()
+    }
+}
+
+impl<T> Clone for PendingAs<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for PendingAs<T> {}
+
+impl<T> Future for PendingAs<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Pending
+    }
+}
+
+/// Creates a future of type `T` that is never ready.
+///
+/// Unlike [`absurd_future`](crate::absurd_future), this needs no inner future at all:
+/// it's a direct replacement for call sites that would otherwise write
+/// `async { future::pending::<()>().await; unreachable!() }` just to get a placeholder
+/// future of the right type. Useful as a placeholder slot alongside
+/// [`Supervisor`](crate::Supervisor), or anywhere a signature demands a future of type
+/// `T` that is never actually expected to resolve.
+///
+/// This mirrors [`std::future::pending`], which is likewise generic over its output type.
+///
+/// # Example
+///
+/// ```
+/// use absurd_future::pending_as;
+/// use std::future::Future;
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll, Waker};
+///
+/// let mut fut = pending_as::<u32>();
+///
+/// let waker = Waker::noop();
+/// let mut cx = Context::from_waker(waker);
+/// assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Pending);
+/// ```
+pub fn pending_as<T>() -> PendingAs<T> {
+    PendingAs::new()
+}
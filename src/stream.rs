@@ -0,0 +1,86 @@
+//! An adapter from a never-yielding [`Stream`] to one yielding any item type.
+
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use crate::Uninhabited;
+
+/// Turn a stream that never yields an item into a stream yielding any desired item type.
+///
+/// This struct is created by the [`absurd_stream`] function. It is the streaming
+/// counterpart to [`AbsurdFuture`](crate::AbsurdFuture): a service stream that logically
+/// produces values forever but whose item type is [`Uninhabited`] (e.g. `Infallible`) can
+/// be adapted to satisfy an interface expecting a concrete item type `T`, such as
+/// `futures::stream::SelectAll<S>` where every other stream in the set yields `T`.
+///
+/// # Example
+///
+/// ```
+/// use absurd_future::absurd_stream;
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+/// use std::convert::Infallible;
+///
+/// // A stream that never yields an `Infallible` item, but can still end.
+/// let empty: stream::Empty<Infallible> = stream::empty();
+/// let adapted = absurd_stream::<_, u32>(empty);
+///
+/// assert_eq!(block_on(adapted.collect::<Vec<_>>()), Vec::<u32>::new());
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct AbsurdStream<S, T> {
+    inner: S,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<S, T> AbsurdStream<S, T> {
+    /// Creates a new `AbsurdStream` that wraps the given stream.
+    ///
+    /// The inner stream must have an [`Uninhabited`] item type, such as `Infallible`.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Unpin for AbsurdStream<S, T> where S: Unpin {}
+
+impl<S, T> Stream for AbsurdStream<S, T>
+where
+    S: Stream,
+    S::Item: Uninhabited,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `inner` is the only structurally pinned field; see the matching
+        // projection in `AbsurdFuture::poll` for the full invariant.
+        let inner: Pin<&mut S> = unsafe { self.map_unchecked_mut(|s| &mut s.inner) };
+        match Stream::poll_next(inner, cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(never)) => Poll::Ready(Some(never.absurd())),
+        }
+    }
+}
+
+/// Wraps a stream that never yields an item and gives it an arbitrary item type.
+///
+/// This function makes it easier to create an [`AbsurdStream`].
+///
+/// # Type Parameters
+///
+/// - `S`: The type of the inner stream, whose item type must be [`Uninhabited`].
+/// - `T`: The desired item type for the wrapped stream. This is often inferred.
+pub fn absurd_stream<S, T>(stream: S) -> AbsurdStream<S, T>
+where
+    S: Stream,
+    S::Item: Uninhabited,
+{
+    AbsurdStream::new(stream)
+}
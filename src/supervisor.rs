@@ -0,0 +1,111 @@
+//! A supervised set of never-returning tasks.
+//!
+//! [`Supervisor`] is the generalization of the hand-rolled supervision loop you'd otherwise
+//! write around a [`tokio::task::JoinSet`]: spawn a handful of background services that are
+//! each expected to run forever, and find out about the first one that errors or panics.
+
+use std::convert::Infallible;
+use std::future::Future;
+
+use tokio::task::{JoinError, JoinSet};
+
+use crate::absurd_future;
+
+/// Supervises a set of tasks that each run forever unless they error.
+///
+/// Every task handed to a `Supervisor` is typed as `Result<Infallible, E>`: since
+/// `Infallible` is uninhabited, the only way a task can actually resolve is by
+/// producing an `Err(E)` or by panicking. [`Supervisor::run`] waits for the first such
+/// event, aborts every other task, and returns the error.
+///
+/// `E` must implement `From<JoinError>` so a panicking task can also be turned into an
+/// `E` by [`Supervisor::run`]; this bound is on the `impl` block that also holds
+/// [`Supervisor::new`], so it is checked at construction, rather than only once `run` is
+/// called.
+///
+/// # Example
+///
+/// ```
+/// use absurd_future::Supervisor;
+/// use std::convert::Infallible;
+/// use std::io;
+///
+/// async fn flaky_task() -> Result<Infallible, io::Error> {
+///     Err(io::Error::other("flaky task exited"))
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut supervisor = Supervisor::new();
+/// supervisor.spawn_fallible(flaky_task());
+///
+/// let error = supervisor.run().await;
+/// assert_eq!(error.to_string(), "flaky task exited");
+/// # }
+/// ```
+pub struct Supervisor<E> {
+    tasks: JoinSet<Result<Infallible, E>>,
+}
+
+impl<E> Supervisor<E>
+where
+    E: From<JoinError> + Send + 'static,
+{
+    /// Creates an empty supervisor.
+    pub fn new() -> Self {
+        Self {
+            tasks: JoinSet::new(),
+        }
+    }
+
+    /// Spawns a task that logically never returns.
+    ///
+    /// `future` is wrapped in [`absurd_future`] so its `Infallible` output slots into the
+    /// same `Result<Infallible, E>` shape as the tasks added via [`Self::spawn_fallible`].
+    pub fn spawn_forever<F>(&mut self, future: F)
+    where
+        F: Future<Output = Infallible> + Send + 'static,
+    {
+        self.tasks.spawn(absurd_future(future));
+    }
+
+    /// Spawns a task that runs forever unless it returns an error.
+    pub fn spawn_fallible<F>(&mut self, future: F)
+    where
+        F: Future<Output = Result<Infallible, E>> + Send + 'static,
+    {
+        self.tasks.spawn(future);
+    }
+
+    /// Runs every spawned task until one of them errors or panics.
+    ///
+    /// A task resolving with `Ok(Infallible)` is impossible (witnessed via `match never {}`
+    /// below), so this only returns once a task yields `Err(E)` or its `JoinHandle` yields a
+    /// [`JoinError`]. Either way, all remaining tasks are aborted before the error is
+    /// returned. If no tasks have been spawned, this future never resolves.
+    pub async fn run(mut self) -> E {
+        loop {
+            match self.tasks.join_next().await {
+                Some(Ok(Ok(never))) => match never {},
+                Some(Ok(Err(e))) => {
+                    self.tasks.abort_all();
+                    return e;
+                }
+                Some(Err(join_err)) => {
+                    self.tasks.abort_all();
+                    return E::from(join_err);
+                }
+                None => std::future::pending().await,
+            }
+        }
+    }
+}
+
+impl<E> Default for Supervisor<E>
+where
+    E: From<JoinError> + Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,52 @@
+//! The [`Uninhabited`] trait: types that are statically known to have no values.
+
+/// A type with no values, witnessing that a piece of code producing it is unreachable.
+///
+/// This is the same idea as [`std::convert::Infallible`], generalized: anything that is
+/// provably empty (an enum with zero variants, or the never type `!` once it is stable and
+/// implements traits) can implement it. [`Uninhabited::absurd`] converts a value of such a
+/// type into any other type `T`, since producing the value in the first place was already
+/// impossible. This lets [`AbsurdFuture`](crate::AbsurdFuture) and
+/// [`AbsurdStream`](crate::AbsurdStream) adapt any such type, not just `Infallible`.
+///
+/// # Safety
+///
+/// Implementors must guarantee that `Self` has no values: no expression can ever actually
+/// evaluate to a `Self`. Implementing this trait for an inhabited type makes `absurd`
+/// unsound, since it would then be possible to call it to conjure a value of any type `T`
+/// out of thin air.
+///
+/// # Example
+///
+/// Implementing `Uninhabited` for your own empty enum:
+///
+/// ```
+/// use absurd_future::Uninhabited;
+///
+/// enum MyNever {}
+///
+/// // SAFETY: `MyNever` is an enum with no variants, so it has no values.
+/// unsafe impl Uninhabited for MyNever {
+///     fn absurd<T>(self) -> T {
+///         match self {}
+///     }
+/// }
+///
+/// fn handle_impossible_case(never: MyNever) -> u32 {
+///     never.absurd()
+/// }
+/// ```
+pub unsafe trait Uninhabited {
+    /// Converts the uninhabited value into any other type.
+    ///
+    /// Because `Self` has no values, this is never actually called at runtime.
+    fn absurd<T>(self) -> T;
+}
+
+// SAFETY: `Infallible` is defined as `enum Infallible {}`, an enum with no variants, so no
+// value of this type can ever be constructed.
+unsafe impl Uninhabited for std::convert::Infallible {
+    fn absurd<T>(self) -> T {
+        match self {}
+    }
+}